@@ -1,6 +1,8 @@
 use std::cell::RefCell;
-use std::rc::Rc;
+use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
 use std::fmt::Debug;
+use std::ops::Bound;
 
 // Color enum for Red-Black tree nodes
 #[derive(Clone, Debug, PartialEq)]
@@ -9,31 +11,58 @@ enum Color {
     Black,
 }
 
+// Which side of its parent a node is linked in as. Tracking this lets the
+// fixup routines pick child/sibling accessors by `side`/`side.opposite()`
+// instead of re-deriving it via `Rc::ptr_eq` against the parent's children.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
 // Type alias for node links using Option and reference counting
-type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+type Link<K, V> = Option<Rc<RefCell<Node<K, V>>>>;
+
+// Type alias for the upward-pointing parent link. This is `Weak` rather than
+// `Rc` so that a parent and its children don't hold a strong reference cycle
+// on each other; without it, dropping the tree would leak every node.
+type ParentLink<K, V> = Option<Weak<RefCell<Node<K, V>>>>;
 
 /**
  * Node structure for Red-Black tree
  * Uses Rc<RefCell<>> for interior mutability and reference counting
  */
 #[derive(Clone, Debug)]
-struct Node<T> {
-    data: T,
+struct Node<K, V> {
+    key: K,
+    value: V,
     color: Color,
-    left: Link<T>,
-    right: Link<T>,
-    parent: Link<T>,
+    left: Link<K, V>,
+    right: Link<K, V>,
+    parent: ParentLink<K, V>,
+    child_of_parent: Option<Side>,
 }
 
-// Node implementation for comparable types
-impl<T: Ord + Debug + Clone> Node<T> {
-    fn new(data: T) -> Rc<RefCell<Self>> {
+// Node implementation for comparable keys
+impl<K: Ord + Debug + Clone, V: Debug + Clone> Node<K, V> {
+    fn new(key: K, value: V) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Node {
-            data,
+            key,
+            value,
             color: Color::Red,
             left: None,
             right: None,
             parent: None,
+            child_of_parent: None,
         }))
     }
 }
@@ -47,46 +76,98 @@ impl<T: Ord + Debug + Clone> Node<T> {
  * 4. Every path from root to leaf has same number of black nodes
  */
 
-pub struct RedBlackTree<T> {
-    root: Link<T>,
-    nil: Rc<RefCell<Node<T>>>,
+pub struct RedBlackTree<K, V> {
+    root: Link<K, V>,
+    nil: Rc<RefCell<Node<K, V>>>,
 }
 
-impl<T: Ord + Debug + Clone + Default> RedBlackTree<T> {
+impl<K: Ord + Debug + Clone + Default, V: Debug + Clone + Default> RedBlackTree<K, V> {
     pub fn new() -> Self {
         let nil = Rc::new(RefCell::new(Node {
-            data: T::default(),
+            key: K::default(),
+            value: V::default(),
             color: Color::Black,
             left: None,
             right: None,
             parent: None,
+            child_of_parent: None,
         }));
 
         RedBlackTree { root: None, nil }
     }
-     /// Inserts a new value into the tree while maintaining Red-Black properties
-    /// Steps:
+
+    /// Upgrades `node`'s `Weak` parent pointer to a strong reference, if the
+    /// parent is still alive (it always is while `node` itself is reachable
+    /// from the tree).
+    fn parent_of(node: &Rc<RefCell<Node<K, V>>>) -> Link<K, V> {
+        node.borrow().parent.clone().and_then(|weak| weak.upgrade())
+    }
+
+    /// Returns `parent`'s child on `side`.
+    fn child(parent: &Rc<RefCell<Node<K, V>>>, side: Side) -> Link<K, V> {
+        match side {
+            Side::Left => parent.borrow().left.clone(),
+            Side::Right => parent.borrow().right.clone(),
+        }
+    }
+
+    /// Links `child` in as `parent`'s child on `side`, keeping `child`'s
+    /// `parent`/`child_of_parent` fields consistent in the same step.
+    /// Insertion, rotation and `transplant` link children through this
+    /// helper; the only direct writes to `child_of_parent` elsewhere clear
+    /// it for a node that becomes the root, and set it on `x` in `delete`
+    /// when `x` stays in place as `y`'s right child.
+    fn set_child(parent: &Rc<RefCell<Node<K, V>>>, side: Side, child: Link<K, V>) {
+        if let Some(c) = &child {
+            c.borrow_mut().parent = Some(Rc::downgrade(parent));
+            c.borrow_mut().child_of_parent = Some(side);
+        }
+        match side {
+            Side::Left => parent.borrow_mut().left = child,
+            Side::Right => parent.borrow_mut().right = child,
+        }
+    }
+
+    /// Rotates `node` down toward `side`, lifting its `side.opposite()` child
+    /// into its place, i.e. `rotate(node, Side::Left)` is a left rotation and
+    /// `rotate(node, Side::Right)` is a right rotation.
+    fn rotate(&mut self, node: Rc<RefCell<Node<K, V>>>, side: Side) {
+        match side {
+            Side::Left => self.left_rotate(node),
+            Side::Right => self.right_rotate(node),
+        }
+    }
+
+     /// Inserts a key/value pair into the tree while maintaining Red-Black properties.
+    /// If `key` is already present, its value is overwritten in place rather than
+    /// inserting a duplicate node. Steps for a new key:
     /// 1. Perform standard BST insertion
     /// 2. Color new node red
     /// 3. Fix Red-Black violations
-    pub fn insert(&mut self, data: T) {
-        let new_node = Node::new(data);
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(existing) = self.find_node(&key) {
+            println!("Key {:?} already present, overwriting value", key);
+            existing.borrow_mut().value = value;
+            return;
+        }
+
+        let new_node = Node::new(key, value);
         new_node.borrow_mut().left = Some(self.nil.clone());
         new_node.borrow_mut().right = Some(self.nil.clone());
-    
+
         if self.root.is_none() {
-            println!("Inserting root: {:?}", new_node.borrow().data);
+            println!("Inserting root: {:?}", new_node.borrow().key);
             self.root = Some(new_node.clone());
             new_node.borrow_mut().color = Color::Black;
             return;
         }
-    
+
         let mut current = self.root.clone();
         let mut parent = None;
-    
+
         while let Some(cur) = current {
             parent = Some(cur.clone());
-            if new_node.borrow().data < cur.borrow().data {
+            if new_node.borrow().key < cur.borrow().key {
                 if Rc::ptr_eq(&cur.borrow().left.as_ref().unwrap(), &self.nil) {
                     break; // Insert here
                 }
@@ -98,60 +179,239 @@ impl<T: Ord + Debug + Clone + Default> RedBlackTree<T> {
                 current = cur.borrow().right.clone();
             }
         }
-    
-        new_node.borrow_mut().parent = parent.clone();
+
         if let Some(p) = parent {
-            if new_node.borrow().data < p.borrow().data {
+            if new_node.borrow().key < p.borrow().key {
                 println!(
                     "Inserting {:?} as left child of {:?}",
-                    new_node.borrow().data,
-                    p.borrow().data
+                    new_node.borrow().key,
+                    p.borrow().key
                 );
-                p.borrow_mut().left = Some(new_node.clone());
+                Self::set_child(&p, Side::Left, Some(new_node.clone()));
             } else {
                 println!(
                     "Inserting {:?} as right child of {:?}",
-                    new_node.borrow().data,
-                    p.borrow().data
+                    new_node.borrow().key,
+                    p.borrow().key
                 );
-                p.borrow_mut().right = Some(new_node.clone());
+                Self::set_child(&p, Side::Right, Some(new_node.clone()));
             }
         }
-    
+
         self.fix_insert(new_node);
     }
-    
+
+    /// Returns a clone of the value stored under `key`, if present.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.find_node(key).map(|n| n.borrow().value.clone())
+    }
+
+    /// Returns `true` if `key` is present in the tree.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find_node(key).is_some()
+    }
+
+    /// Returns the value stored under `key`, inserting `default` under that
+    /// key first if it is not already present.
+    pub fn get_or_insert(&mut self, key: K, default: V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        self.insert(key, default.clone());
+        default
+    }
+
+    // `Index<&K>` is intentionally not implemented: a value lives behind its
+    // node's `RefCell`, and `Index::index` must return `&V` without taking
+    // ownership of a `Ref` guard to keep alive. The only ways to do that are
+    // an unsafe pointer cast that defeats `RefCell`'s own runtime borrow
+    // tracking (unsound the moment any `&self` method ever borrows the same
+    // node mutably) or `Ref::leak`, which permanently marks the node
+    // borrowed and would break every later `get`/`insert`/`delete` on it.
+    // Neither is worth it for a `tree[&k]` convenience when `get` already
+    // hands back an owned clone; use that instead.
+
+    /// Returns the key that immediately follows `key` in ascending order,
+    /// if any. Follows the classic rule: the right subtree's minimum if one
+    /// exists, otherwise the nearest ancestor of which `key`'s node is a
+    /// left descendant.
+    pub fn successor(&self, key: &K) -> Option<K> {
+        let node = self.find_node(key)?;
+        self.in_order_successor(&node).map(|n| n.borrow().key.clone())
+    }
+
+    /// Returns the key that immediately precedes `key` in ascending order,
+    /// if any. Mirrors `successor`: the left subtree's maximum if one
+    /// exists, otherwise the nearest ancestor of which `key`'s node is a
+    /// right descendant.
+    pub fn predecessor(&self, key: &K) -> Option<K> {
+        let node = self.find_node(key)?;
+        self.in_order_predecessor(&node).map(|n| n.borrow().key.clone())
+    }
+
+    /// Returns the lowest common ancestor of `a` and `b`, if both are
+    /// present. Locates both nodes, then walks the deeper one's parent
+    /// chain up to the shallower node's depth, and finally climbs both in
+    /// lockstep until the paths meet. Runs in O(height) time using only
+    /// the parent links already stored on each node, no extra space.
+    pub fn lowest_common_ancestor(&self, a: &K, b: &K) -> Option<K> {
+        let mut node_a = self.find_node(a)?;
+        let mut node_b = self.find_node(b)?;
+
+        let mut depth_a = Self::depth(&node_a);
+        let mut depth_b = Self::depth(&node_b);
+
+        while depth_a > depth_b {
+            node_a = Self::parent_of(&node_a).unwrap();
+            depth_a -= 1;
+        }
+        while depth_b > depth_a {
+            node_b = Self::parent_of(&node_b).unwrap();
+            depth_b -= 1;
+        }
+
+        while !Rc::ptr_eq(&node_a, &node_b) {
+            node_a = Self::parent_of(&node_a).unwrap();
+            node_b = Self::parent_of(&node_b).unwrap();
+        }
+
+        let key = node_a.borrow().key.clone();
+        Some(key)
+    }
+
+    /// Returns the number of parent links from `node` up to the root
+    /// (0 for the root itself).
+    fn depth(node: &Rc<RefCell<Node<K, V>>>) -> usize {
+        let mut depth = 0;
+        let mut current = node.clone();
+        while let Some(p) = Self::parent_of(&current) {
+            depth += 1;
+            current = p;
+        }
+        depth
+    }
+
+    /// Returns an iterator over `(K, V)` pairs in ascending key order.
+    ///
+    /// Pairs are cloned out of the tree rather than borrowed: the key and
+    /// value live in separate `Node` fields behind a `RefCell`, so there is
+    /// no `&(K, V)` to hand back without holding every visited node's borrow
+    /// open for the iterator's whole lifetime.
+    pub fn iter(&self) -> Iter<K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            nil: self.nil.clone(),
+        };
+        iter.push_left_spine(self.root.clone());
+        iter
+    }
+
+    /// Returns a breadth-first iterator over `(K, V)` pairs, rank by rank.
+    pub fn level_order(&self) -> LevelOrderIter<K, V> {
+        let mut queue = VecDeque::new();
+        if let Some(root) = self.root.clone() {
+            if !Rc::ptr_eq(&root, &self.nil) {
+                queue.push_back(root);
+            }
+        }
+        LevelOrderIter { queue, nil: self.nil.clone() }
+    }
+
+    /// Returns an iterator over `(K, V)` pairs whose keys fall within
+    /// `[lower, upper]` (respecting `Bound::Excluded`/`Bound::Unbounded`),
+    /// in ascending order. Descends once to the first in-range node, then
+    /// walks forward via in-order successor, stopping as soon as a key
+    /// exceeds `upper`.
+    pub fn range<'a>(&'a self, lower: Bound<&K>, upper: Bound<&'a K>) -> Range<'a, K, V> {
+        Range {
+            tree: self,
+            current: self.find_first_in_range(lower),
+            upper,
+        }
+    }
+
+    /// Descends to the first node whose key satisfies `lower`, tracking the
+    /// best candidate seen so far as the search narrows.
+    fn find_first_in_range(&self, lower: Bound<&K>) -> Link<K, V> {
+        let mut current = self.root.clone();
+        let mut candidate = None;
+
+        while let Some(cur) = current {
+            if Rc::ptr_eq(&cur, &self.nil) {
+                break;
+            }
+            let in_bounds = match lower {
+                Bound::Unbounded => true,
+                Bound::Included(k) => cur.borrow().key >= *k,
+                Bound::Excluded(k) => cur.borrow().key > *k,
+            };
+            if in_bounds {
+                candidate = Some(cur.clone());
+                current = cur.borrow().left.clone();
+            } else {
+                current = cur.borrow().right.clone();
+            }
+        }
+
+        candidate
+    }
+
+    /// Returns the in-order successor of `node`: the right subtree's
+    /// minimum if one exists, otherwise the nearest ancestor of which
+    /// `node` is a left descendant.
+    fn in_order_successor(&self, node: &Rc<RefCell<Node<K, V>>>) -> Link<K, V> {
+        let right = node.borrow().right.clone().unwrap();
+        if !Rc::ptr_eq(&right, &self.nil) {
+            return Some(self.minimum(right));
+        }
+
+        let mut current = node.clone();
+        while let Some(p) = Self::parent_of(&current) {
+            if !Rc::ptr_eq(&current, p.borrow().right.as_ref().unwrap()) {
+                return Some(p);
+            }
+            current = p;
+        }
+        None
+    }
+
+    /// Returns the in-order predecessor of `node`: the left subtree's
+    /// maximum if one exists, otherwise the nearest ancestor of which
+    /// `node` is a right descendant.
+    fn in_order_predecessor(&self, node: &Rc<RefCell<Node<K, V>>>) -> Link<K, V> {
+        let left = node.borrow().left.clone().unwrap();
+        if !Rc::ptr_eq(&left, &self.nil) {
+            return Some(self.maximum(left));
+        }
+
+        let mut current = node.clone();
+        while let Some(p) = Self::parent_of(&current) {
+            if !Rc::ptr_eq(&current, p.borrow().left.as_ref().unwrap()) {
+                return Some(p);
+            }
+            current = p;
+        }
+        None
+    }
 
      /// Fixes Red-Black tree violations after insertion
     /// Cases:
     /// 1. Uncle is red -> Recolor
     /// 2. Uncle is black (triangle) -> Rotate
     /// 3. Uncle is black (line) -> Rotate and recolor
-    fn fix_insert(&mut self, node: Rc<RefCell<Node<T>>>) {
+    fn fix_insert(&mut self, node: Rc<RefCell<Node<K, V>>>) {
         let mut current_node = node;
 
-        while let Some(parent_rc) = {
-            let borrow = current_node.borrow();
-            borrow.parent.clone()
-        } {
+        while let Some(parent_rc) = Self::parent_of(&current_node) {
             if parent_rc.borrow().color == Color::Black {
                 break;
             }
 
-            let grandparent_rc = {
-                let parent_borrow = parent_rc.borrow();
-                parent_borrow.parent.clone()
-            };
+            let grandparent_rc = Self::parent_of(&parent_rc);
 
             if let Some(gp_rc) = grandparent_rc {
-                let (uncle_rc, is_left_child) = {
-                    let gp_borrow = gp_rc.borrow();
-                    if Rc::ptr_eq(&parent_rc, gp_borrow.left.as_ref().unwrap()) {
-                        (gp_borrow.right.clone(), true)
-                    } else {
-                        (gp_borrow.left.clone(), false)
-                    }
-                };
+                let side = parent_rc.borrow().child_of_parent.unwrap();
+                let uncle_rc = Self::child(&gp_rc, side.opposite());
 
                 if let Some(uncle_rc) = uncle_rc {
                     if uncle_rc.borrow().color == Color::Red {
@@ -165,35 +425,19 @@ impl<T: Ord + Debug + Clone + Default> RedBlackTree<T> {
                     }
                 }
 
-                if is_left_child {
-                    {
-                        let parent_borrow = parent_rc.borrow();
-                        if Rc::ptr_eq(&current_node, parent_borrow.right.as_ref().unwrap()) {
-                            drop(parent_borrow);
-                            current_node = parent_rc.clone();
-                            println!("Left rotate at {:?}", current_node.borrow().data);
-                            self.left_rotate(current_node.clone());
-                        }
-                    }
-                    println!("Right rotate at {:?}", gp_rc.borrow().data);
-                    parent_rc.borrow_mut().color = Color::Black;
-                    gp_rc.borrow_mut().color = Color::Red;
-                    self.right_rotate(gp_rc.clone());
-                } else {
-                    {
-                        let parent_borrow = parent_rc.borrow();
-                        if Rc::ptr_eq(&current_node, parent_borrow.left.as_ref().unwrap()) {
-                            drop(parent_borrow);
-                            current_node = parent_rc.clone();
-                            println!("Right rotate at {:?}", current_node.borrow().data);
-                            self.right_rotate(current_node.clone());
-                        }
-                    }
-                    println!("Left rotate at {:?}", gp_rc.borrow().data);
-                    parent_rc.borrow_mut().color = Color::Black;
-                    gp_rc.borrow_mut().color = Color::Red;
-                    self.left_rotate(gp_rc.clone());
+                if current_node.borrow().child_of_parent == Some(side.opposite()) {
+                    current_node = parent_rc.clone();
+                    println!("Rotate at {:?}", current_node.borrow().key);
+                    self.rotate(current_node.clone(), side);
                 }
+
+                let new_parent = Self::parent_of(&current_node).unwrap();
+                let new_grandparent = Self::parent_of(&new_parent).unwrap();
+
+                println!("Rotate at {:?}", new_grandparent.borrow().key);
+                new_parent.borrow_mut().color = Color::Black;
+                new_grandparent.borrow_mut().color = Color::Red;
+                self.rotate(new_grandparent, side.opposite());
             }
         }
 
@@ -210,26 +454,23 @@ impl<T: Ord + Debug + Clone + Default> RedBlackTree<T> {
     ///   a   y    =>     x   c
     ///      / \         / \
     ///     b   c       a   b
-    fn left_rotate(&mut self, x: Rc<RefCell<Node<T>>>) {
+    fn left_rotate(&mut self, x: Rc<RefCell<Node<K, V>>>) {
         let y = x.borrow().right.clone().unwrap();
-        x.borrow_mut().right = y.borrow().left.clone();
-
-        if let Some(left) = y.borrow().left.clone() {
-            left.borrow_mut().parent = Some(x.clone());
-        }
-
-        y.borrow_mut().parent = x.borrow().parent.clone();
+        Self::set_child(&x, Side::Right, Some(y.borrow().left.clone().unwrap()));
 
-        if x.borrow().parent.is_none() {
-            self.root = Some(y.clone());
-        } else if Rc::ptr_eq(&x, &x.borrow().parent.as_ref().unwrap().borrow().left.as_ref().unwrap()) {
-            x.borrow().parent.as_ref().unwrap().borrow_mut().left = Some(y.clone());
-        } else {
-            x.borrow().parent.as_ref().unwrap().borrow_mut().right = Some(y.clone());
+        match Self::parent_of(&x) {
+            None => {
+                y.borrow_mut().parent = None;
+                y.borrow_mut().child_of_parent = None;
+                self.root = Some(y.clone());
+            }
+            Some(x_parent) => {
+                let side = x.borrow().child_of_parent.unwrap();
+                Self::set_child(&x_parent, side, Some(y.clone()));
+            }
         }
 
-        y.borrow_mut().left = Some(x.clone());
-        x.borrow_mut().parent = Some(y.clone());
+        Self::set_child(&y, Side::Left, Some(x));
     }
 
     /// Performs right rotation around given node
@@ -240,56 +481,962 @@ impl<T: Ord + Debug + Clone + Default> RedBlackTree<T> {
     ///     x   c  =>   a   y
     ///    / \             / \
     ///   a   b           b   c
-    fn right_rotate(&mut self, y: Rc<RefCell<Node<T>>>) {
+    fn right_rotate(&mut self, y: Rc<RefCell<Node<K, V>>>) {
         let x = y.borrow().left.clone().unwrap();
-        y.borrow_mut().left = x.borrow().right.clone();
+        Self::set_child(&y, Side::Left, Some(x.borrow().right.clone().unwrap()));
 
-        if let Some(right) = x.borrow().right.clone() {
-            right.borrow_mut().parent = Some(y.clone());
+        match Self::parent_of(&y) {
+            None => {
+                x.borrow_mut().parent = None;
+                x.borrow_mut().child_of_parent = None;
+                self.root = Some(x.clone());
+            }
+            Some(y_parent) => {
+                let side = y.borrow().child_of_parent.unwrap();
+                Self::set_child(&y_parent, side, Some(x.clone()));
+            }
         }
 
-        x.borrow_mut().parent = y.borrow().parent.clone();
+        Self::set_child(&x, Side::Right, Some(y));
+    }
+    /// Removes `key` (and its value) from the tree, restoring Red-Black properties
+    /// afterwards. Returns `true` if a matching node was found and removed.
+    ///
+    /// Follows the CLRS transplant-based deletion: locate the node `z` to
+    /// remove, determine the node `y` that is actually spliced out (either
+    /// `z` itself, or its in-order successor when `z` has two children), and
+    /// the child `x` that takes `y`'s place. If `y` was black, removing it
+    /// may shorten some root-to-leaf paths, so `delete_fixup` restores the
+    /// black-height starting from `x`.
+    pub fn delete(&mut self, key: &K) -> bool {
+        let z = match self.find_node(key) {
+            Some(z) => z,
+            None => return false,
+        };
+
+        let mut y = z.clone();
+        let mut y_original_color = y.borrow().color.clone();
+        let x;
 
-        if y.borrow().parent.is_none() {
-            self.root = Some(x.clone());
-        } else if Rc::ptr_eq(&y, &y.borrow().parent.as_ref().unwrap().borrow().right.as_ref().unwrap()) {
-            y.borrow().parent.as_ref().unwrap().borrow_mut().right = Some(x.clone());
+        if Rc::ptr_eq(z.borrow().left.as_ref().unwrap(), &self.nil) {
+            x = z.borrow().right.clone().unwrap();
+            self.transplant(&z, &x);
+        } else if Rc::ptr_eq(z.borrow().right.as_ref().unwrap(), &self.nil) {
+            x = z.borrow().left.clone().unwrap();
+            self.transplant(&z, &x);
         } else {
-            y.borrow().parent.as_ref().unwrap().borrow_mut().left = Some(x.clone());
+            y = self.minimum(z.borrow().right.clone().unwrap());
+            y_original_color = y.borrow().color.clone();
+            x = y.borrow().right.clone().unwrap();
+
+            if Rc::ptr_eq(&Self::parent_of(&y).unwrap(), &z) {
+                x.borrow_mut().parent = Some(Rc::downgrade(&y));
+                x.borrow_mut().child_of_parent = Some(Side::Right);
+            } else {
+                self.transplant(&y, &x);
+                Self::set_child(&y, Side::Right, z.borrow().right.clone());
+            }
+
+            self.transplant(&z, &y);
+            Self::set_child(&y, Side::Left, z.borrow().left.clone());
+            y.borrow_mut().color = z.borrow().color.clone();
+        }
+
+        if y_original_color == Color::Black {
+            self.delete_fixup(x);
+        }
+
+        // The sentinel can end up standing in for the root of an emptied
+        // tree; fold that back down to `None` so callers keep seeing an
+        // empty tree rather than a dangling nil root.
+        if let Some(root_rc) = &self.root {
+            if Rc::ptr_eq(root_rc, &self.nil) {
+                self.root = None;
+            }
+        }
+
+        true
+    }
+
+    /// Finds the node holding `key`, if any.
+    fn find_node(&self, key: &K) -> Option<Rc<RefCell<Node<K, V>>>> {
+        let mut current = self.root.clone();
+        while let Some(cur) = current {
+            if Rc::ptr_eq(&cur, &self.nil) {
+                return None;
+            }
+            if *key == cur.borrow().key {
+                return Some(cur);
+            } else if *key < cur.borrow().key {
+                current = cur.borrow().left.clone();
+            } else {
+                current = cur.borrow().right.clone();
+            }
+        }
+        None
+    }
+
+    /// Returns the minimum (leftmost) node of the subtree rooted at `node`.
+    fn minimum(&self, node: Rc<RefCell<Node<K, V>>>) -> Rc<RefCell<Node<K, V>>> {
+        let mut current = node;
+        loop {
+            let left = current.borrow().left.clone().unwrap();
+            if Rc::ptr_eq(&left, &self.nil) {
+                return current;
+            }
+            current = left;
+        }
+    }
+
+    /// Returns the maximum (rightmost) node of the subtree rooted at `node`.
+    fn maximum(&self, node: Rc<RefCell<Node<K, V>>>) -> Rc<RefCell<Node<K, V>>> {
+        let mut current = node;
+        loop {
+            let right = current.borrow().right.clone().unwrap();
+            if Rc::ptr_eq(&right, &self.nil) {
+                return current;
+            }
+            current = right;
+        }
+    }
+
+    /// Replaces the subtree rooted at `u` with the subtree rooted at `v`,
+    /// reattaching `v` to `u`'s parent. `v` may be the `nil` sentinel, in
+    /// which case its `parent` is temporarily repointed so `delete_fixup`
+    /// can still find its way back up the tree.
+    fn transplant(&mut self, u: &Rc<RefCell<Node<K, V>>>, v: &Rc<RefCell<Node<K, V>>>) {
+        match Self::parent_of(u) {
+            None => {
+                v.borrow_mut().parent = None;
+                v.borrow_mut().child_of_parent = None;
+                self.root = Some(v.clone());
+            }
+            Some(p) => {
+                let side = u.borrow().child_of_parent.unwrap();
+                Self::set_child(&p, side, Some(v.clone()));
+            }
+        }
+    }
+
+    /// Restores Red-Black properties after a black node has been removed.
+    /// `x` is the node that moved into the removed node's place, possibly
+    /// the `nil` sentinel carrying the extra "double black" to fix up.
+    /// Cases (mirrored for the right-child side):
+    /// 1. Sibling `w` is red -> recolor and rotate toward `x`.
+    /// 2. Both of `w`'s children are black -> recolor `w` red, move up.
+    /// 3. `w`'s far child is black -> recolor and rotate to fall into case 4.
+    /// 4. `w`'s far child is red -> recolor using the parent's color,
+    ///    blacken the far nephew, rotate around the parent and terminate.
+    fn delete_fixup(&mut self, node: Rc<RefCell<Node<K, V>>>) {
+        let mut x = node;
+
+        while !Rc::ptr_eq(&x, self.root.as_ref().unwrap()) && x.borrow().color == Color::Black {
+            let parent = Self::parent_of(&x).unwrap();
+            let side = x.borrow().child_of_parent.unwrap();
+            let mut w = Self::child(&parent, side.opposite()).unwrap();
+
+            if w.borrow().color == Color::Red {
+                println!("Sibling red: recolor and rotate at {:?}", parent.borrow().key);
+                w.borrow_mut().color = Color::Black;
+                parent.borrow_mut().color = Color::Red;
+                self.rotate(parent.clone(), side);
+                w = Self::child(&parent, side.opposite()).unwrap();
+            }
+
+            let near_black = Self::child(&w, side).unwrap().borrow().color == Color::Black;
+            let far_black = Self::child(&w, side.opposite()).unwrap().borrow().color == Color::Black;
+
+            if near_black && far_black {
+                println!("Sibling's children both black: recolor sibling, move up");
+                w.borrow_mut().color = Color::Red;
+                x = parent;
+            } else {
+                if far_black {
+                    println!("Sibling's far child black: rotate at sibling");
+                    Self::child(&w, side).unwrap().borrow_mut().color = Color::Black;
+                    w.borrow_mut().color = Color::Red;
+                    self.rotate(w.clone(), side.opposite());
+                    w = Self::child(&parent, side.opposite()).unwrap();
+                }
+
+                println!("Sibling's far child red: rotate at {:?}", parent.borrow().key);
+                w.borrow_mut().color = parent.borrow().color.clone();
+                parent.borrow_mut().color = Color::Black;
+                Self::child(&w, side.opposite()).unwrap().borrow_mut().color = Color::Black;
+                self.rotate(parent.clone(), side);
+                x = self.root.clone().unwrap();
+            }
         }
 
-        x.borrow_mut().right = Some(y.clone());
-        y.borrow_mut().parent = Some(x.clone());
+        x.borrow_mut().color = Color::Black;
     }
+
      /// Performs in-order traversal of the tree
     /// Visits nodes in ascending order
     pub fn inorder(&self) {
         self.inorder_helper(self.root.clone());
     }
 
-    fn inorder_helper(&self, node: Link<T>) {
+    fn inorder_helper(&self, node: Link<K, V>) {
         if let Some(n) = node {
             if Rc::ptr_eq(&n, &self.nil) {
                 return;
             }
             self.inorder_helper(n.borrow().left.clone());
-            println!("{:?} ({:?})", n.borrow().data, n.borrow().color);
+            println!("{:?} => {:?} ({:?})", n.borrow().key, n.borrow().value, n.borrow().color);
             self.inorder_helper(n.borrow().right.clone());
         }
     }
+
+    /// Verifies the tree's invariants (root is black, no red node has a
+    /// red child, every root-to-`nil` path has the same black count, and
+    /// BST ordering holds) and returns the tree's black-height on
+    /// success. On failure, names the first violated property and the
+    /// offending key.
+    pub fn validate(&self) -> Result<usize, String> {
+        if let Some(root) = &self.root {
+            if root.borrow().color != Color::Black {
+                return Err(format!(
+                    "root is not black: key {:?} is {:?}",
+                    root.borrow().key,
+                    root.borrow().color
+                ));
+            }
+        }
+
+        self.validate_node(self.root.clone(), None, None)
+    }
+
+    /// Recursively checks BST ordering, the no-red-red-child rule, and
+    /// equal black-height for the subtree rooted at `node`, whose keys
+    /// must lie strictly between `lower` and `upper`. Returns the
+    /// subtree's black-height.
+    fn validate_node(
+        &self,
+        node: Link<K, V>,
+        lower: Option<&K>,
+        upper: Option<&K>,
+    ) -> Result<usize, String> {
+        let node = match node {
+            Some(n) if !Rc::ptr_eq(&n, &self.nil) => n,
+            _ => return Ok(1),
+        };
+
+        let key = node.borrow().key.clone();
+
+        if let Some(lower) = lower {
+            if key <= *lower {
+                return Err(format!(
+                    "BST property violated: key {:?} is not greater than ancestor {:?}",
+                    key, lower
+                ));
+            }
+        }
+        if let Some(upper) = upper {
+            if key >= *upper {
+                return Err(format!(
+                    "BST property violated: key {:?} is not less than ancestor {:?}",
+                    key, upper
+                ));
+            }
+        }
+
+        if node.borrow().color == Color::Red {
+            for child in [node.borrow().left.clone(), node.borrow().right.clone()]
+                .into_iter()
+                .flatten()
+            {
+                if child.borrow().color == Color::Red {
+                    return Err(format!(
+                        "red-red violation: key {:?} has a red child {:?}",
+                        key,
+                        child.borrow().key
+                    ));
+                }
+            }
+        }
+
+        let left_height = self.validate_node(node.borrow().left.clone(), lower, Some(&key))?;
+        let right_height = self.validate_node(node.borrow().right.clone(), Some(&key), upper)?;
+
+        if left_height != right_height {
+            return Err(format!(
+                "black-height mismatch at key {:?}: left subtree has {}, right subtree has {}",
+                key, left_height, right_height
+            ));
+        }
+
+        let is_black = node.borrow().color == Color::Black;
+        Ok(left_height + if is_black { 1 } else { 0 })
+    }
+}
+
+/// Tears the tree down iteratively rather than relying on `Node`'s derived
+/// recursive drop glue, which would walk the whole left/right spine on the
+/// stack and could overflow it for a deep tree. Parent links are `Weak`, so
+/// there is no reference cycle left to worry about here; this impl is only
+/// about bounding stack depth during teardown.
+impl<K, V> Drop for RedBlackTree<K, V> {
+    fn drop(&mut self) {
+        let mut pending = Vec::new();
+        if let Some(root) = self.root.take() {
+            pending.push(root);
+        }
+
+        while let Some(node) = pending.pop() {
+            let mut node_mut = node.borrow_mut();
+            if let Some(left) = node_mut.left.take() {
+                if !Rc::ptr_eq(&left, &self.nil) {
+                    pending.push(left);
+                }
+            }
+            if let Some(right) = node_mut.right.take() {
+                if !Rc::ptr_eq(&right, &self.nil) {
+                    pending.push(right);
+                }
+            }
+        }
+    }
+}
+
+/// Ascending in-order iterator produced by [`RedBlackTree::iter`]. Holds
+/// the ancestors still owing a visit on a stack, in the usual "unrolled
+/// recursion" style of a BST in-order iterator.
+pub struct Iter<K, V> {
+    stack: Vec<Rc<RefCell<Node<K, V>>>>,
+    nil: Rc<RefCell<Node<K, V>>>,
+}
+
+impl<K: Ord + Debug + Clone, V: Debug + Clone> Iter<K, V> {
+    fn push_left_spine(&mut self, mut node: Link<K, V>) {
+        while let Some(n) = node {
+            if Rc::ptr_eq(&n, &self.nil) {
+                break;
+            }
+            node = n.borrow().left.clone();
+            self.stack.push(n);
+        }
+    }
+}
+
+impl<K: Ord + Debug + Clone, V: Debug + Clone> Iterator for Iter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let item = (node.borrow().key.clone(), node.borrow().value.clone());
+        let right = node.borrow().right.clone();
+        self.push_left_spine(right);
+        Some(item)
+    }
+}
+
+/// Breadth-first iterator produced by [`RedBlackTree::level_order`].
+pub struct LevelOrderIter<K, V> {
+    queue: VecDeque<Rc<RefCell<Node<K, V>>>>,
+    nil: Rc<RefCell<Node<K, V>>>,
+}
+
+impl<K: Ord + Debug + Clone, V: Debug + Clone> Iterator for LevelOrderIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        let item = (node.borrow().key.clone(), node.borrow().value.clone());
+
+        for child in [node.borrow().left.clone(), node.borrow().right.clone()]
+            .into_iter()
+            .flatten()
+        {
+            if !Rc::ptr_eq(&child, &self.nil) {
+                self.queue.push_back(child);
+            }
+        }
+
+        Some(item)
+    }
+}
+
+/// Bounded ascending iterator produced by [`RedBlackTree::range`].
+pub struct Range<'a, K, V> {
+    tree: &'a RedBlackTree<K, V>,
+    current: Link<K, V>,
+    upper: Bound<&'a K>,
+}
+
+impl<'a, K: Ord + Debug + Clone + Default, V: Debug + Clone + Default> Iterator for Range<'a, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.clone()?;
+
+        let in_bounds = match self.upper {
+            Bound::Unbounded => true,
+            Bound::Included(k) => node.borrow().key <= *k,
+            Bound::Excluded(k) => node.borrow().key < *k,
+        };
+        if !in_bounds {
+            self.current = None;
+            return None;
+        }
+
+        let item = (node.borrow().key.clone(), node.borrow().value.clone());
+        self.current = self.tree.in_order_successor(&node);
+        Some(item)
+    }
+}
+
+impl<K: Ord + Debug + Clone + Default, V: Debug + Clone + Default> FromIterator<(K, V)> for RedBlackTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = RedBlackTree::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+/// Owned iterator produced by [`RedBlackTree::into_iter`].
+///
+/// Keeps the tree alive alongside the traversal stack: `Drop` for
+/// `RedBlackTree` tears down every node's child links as it walks, so
+/// dropping the tree before the iterator finishes would sever subtrees it
+/// hasn't visited yet. Fields drop in declaration order, so `iter` releases
+/// its `Rc`s before `_tree` runs its own teardown.
+pub struct IntoIter<K, V> {
+    iter: Iter<K, V>,
+    _tree: RedBlackTree<K, V>,
+}
+
+impl<K: Ord + Debug + Clone, V: Debug + Clone> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<K: Ord + Debug + Clone + Default, V: Debug + Clone + Default> IntoIterator for RedBlackTree<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let iter = self.iter();
+        IntoIter { iter, _tree: self }
+    }
 }
 
 fn main() {
-    let mut tree = RedBlackTree::new();
-    tree.insert(20);
-    tree.insert(15);
-    tree.insert(25);
-    tree.insert(10);
-    tree.insert(5);
-    tree.insert(1);
-    tree.insert(30);
-    tree.insert(18);
+    let mut tree: RedBlackTree<i32, &str> = RedBlackTree::new();
+    tree.insert(20, "twenty");
+    tree.insert(15, "fifteen");
+    tree.insert(25, "twenty-five");
+    tree.insert(10, "ten");
+    tree.insert(5, "five");
+    tree.insert(1, "one");
+    tree.insert(30, "thirty");
+    tree.insert(18, "eighteen");
 
     println!("In-order traversal of the Red-Black Tree:");
     tree.inorder();
+
+    println!("get(&15) = {:?}", tree.get(&15));
+    println!("contains_key(&100) = {}", tree.contains_key(&100));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a node of the given `color` whose children are both the `nil`
+    /// sentinel.
+    fn leaf(nil: &Rc<RefCell<Node<i32, i32>>>, key: i32, color: Color) -> Rc<RefCell<Node<i32, i32>>> {
+        let node = Node::new(key, key);
+        node.borrow_mut().color = color;
+        node.borrow_mut().left = Some(nil.clone());
+        node.borrow_mut().right = Some(nil.clone());
+        node
+    }
+
+    #[test]
+    fn delete_leaf_node() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+
+        assert!(tree.delete(&5));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&5));
+        assert_eq!(
+            tree.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            vec![10, 15, 20, 25, 30, 35]
+        );
+    }
+
+    #[test]
+    fn delete_root_node() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+
+        assert!(tree.delete(&20));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&20));
+        assert_eq!(
+            tree.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            vec![5, 10, 15, 25, 30, 35]
+        );
+    }
+
+    #[test]
+    fn delete_two_children_node() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+
+        // 10 has two children (5 and 15); its in-order successor (15) is
+        // spliced into its place.
+        assert!(tree.delete(&10));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&10));
+        assert_eq!(
+            tree.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            vec![5, 15, 20, 25, 30, 35]
+        );
+    }
+
+    #[test]
+    fn delete_missing_key_returns_false() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(1, 1);
+        assert!(!tree.delete(&42));
+    }
+
+    /// `delete_fixup` case 1: `x`'s sibling is red. Deleting the black leaf
+    /// `3` leaves the `nil` in its place under `5` with a red sibling (`8`),
+    /// forcing a rotation at `5` before falling into case 2, which ends at
+    /// the now-red `5`.
+    #[test]
+    fn delete_fixup_case1_red_sibling() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n3 = leaf(&nil, 3, Color::Black);
+        let n7 = leaf(&nil, 7, Color::Black);
+        let n9 = leaf(&nil, 9, Color::Black);
+        let n8 = Node::new(8, 8);
+        n8.borrow_mut().color = Color::Red;
+        RedBlackTree::<i32, i32>::set_child(&n8, Side::Left, Some(n7));
+        RedBlackTree::<i32, i32>::set_child(&n8, Side::Right, Some(n9));
+
+        let n5 = Node::new(5, 5);
+        n5.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Left, Some(n3));
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Right, Some(n8));
+
+        let n13 = leaf(&nil, 13, Color::Black);
+        let n17 = leaf(&nil, 17, Color::Black);
+        let n15 = Node::new(15, 15);
+        n15.borrow_mut().color = Color::Red;
+        RedBlackTree::<i32, i32>::set_child(&n15, Side::Left, Some(n13));
+        RedBlackTree::<i32, i32>::set_child(&n15, Side::Right, Some(n17));
+
+        let n25 = leaf(&nil, 25, Color::Black);
+        let n35 = leaf(&nil, 35, Color::Black);
+        let n30 = Node::new(30, 30);
+        n30.borrow_mut().color = Color::Red;
+        RedBlackTree::<i32, i32>::set_child(&n30, Side::Left, Some(n25));
+        RedBlackTree::<i32, i32>::set_child(&n30, Side::Right, Some(n35));
+
+        let n20 = Node::new(20, 20);
+        n20.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n20, Side::Left, Some(n15));
+        RedBlackTree::<i32, i32>::set_child(&n20, Side::Right, Some(n30));
+
+        let n10 = Node::new(10, 10);
+        n10.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n10, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&n10, Side::Right, Some(n20));
+        tree.root = Some(n10);
+
+        assert_eq!(tree.validate(), Ok(4));
+        assert!(tree.delete(&3));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&3));
+    }
+
+    /// `delete_fixup` case 2: sibling is black and both its children are
+    /// black, so the fixup recolors the sibling red and moves the double
+    /// black up to the (red) parent, which terminates the loop immediately.
+    #[test]
+    fn delete_fixup_case2_both_nephews_black() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n3 = leaf(&nil, 3, Color::Black);
+        let n8 = leaf(&nil, 8, Color::Black);
+        let n5 = Node::new(5, 5);
+        n5.borrow_mut().color = Color::Red;
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Left, Some(n3));
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Right, Some(n8));
+
+        let n50 = leaf(&nil, 50, Color::Black);
+
+        let root = Node::new(30, 30);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n50));
+        tree.root = Some(root);
+
+        assert_eq!(tree.validate(), Ok(3));
+        assert!(tree.delete(&3));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&3));
+        assert_eq!(tree.get(&5), Some(5));
+    }
+
+    /// `delete_fixup` case 3: sibling is black, its near nephew is red and
+    /// its far nephew is black, so the fixup rotates at the sibling to
+    /// convert this into case 4.
+    #[test]
+    fn delete_fixup_case3_near_red_far_black() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n3 = leaf(&nil, 3, Color::Black);
+        let n6 = leaf(&nil, 6, Color::Red);
+        let n8 = Node::new(8, 8);
+        n8.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n8, Side::Left, Some(n6));
+        n8.borrow_mut().right = Some(nil.clone());
+
+        let n5 = Node::new(5, 5);
+        n5.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Left, Some(n3));
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Right, Some(n8));
+
+        let n45 = leaf(&nil, 45, Color::Black);
+        let n55 = leaf(&nil, 55, Color::Black);
+        let n50 = Node::new(50, 50);
+        n50.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n50, Side::Left, Some(n45));
+        RedBlackTree::<i32, i32>::set_child(&n50, Side::Right, Some(n55));
+
+        let root = Node::new(30, 30);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n50));
+        tree.root = Some(root);
+
+        assert_eq!(tree.validate(), Ok(4));
+        assert!(tree.delete(&3));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&3));
+        assert_eq!(tree.get(&6), Some(6));
+        assert_eq!(tree.get(&8), Some(8));
+    }
+
+    /// `delete_fixup` case 4: sibling is black and its far nephew is red,
+    /// so the fixup recolors and rotates at the parent, terminating
+    /// directly without needing case 3 first.
+    #[test]
+    fn delete_fixup_case4_far_red() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n3 = leaf(&nil, 3, Color::Black);
+        let n9 = leaf(&nil, 9, Color::Red);
+        let n8 = Node::new(8, 8);
+        n8.borrow_mut().color = Color::Black;
+        n8.borrow_mut().left = Some(nil.clone());
+        RedBlackTree::<i32, i32>::set_child(&n8, Side::Right, Some(n9));
+
+        let n5 = Node::new(5, 5);
+        n5.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Left, Some(n3));
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Right, Some(n8));
+
+        let n45 = leaf(&nil, 45, Color::Black);
+        let n55 = leaf(&nil, 55, Color::Black);
+        let n50 = Node::new(50, 50);
+        n50.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n50, Side::Left, Some(n45));
+        RedBlackTree::<i32, i32>::set_child(&n50, Side::Right, Some(n55));
+
+        let root = Node::new(30, 30);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n50));
+        tree.root = Some(root);
+
+        assert_eq!(tree.validate(), Ok(4));
+        assert!(tree.delete(&3));
+        assert!(tree.validate().is_ok());
+        assert!(!tree.contains_key(&3));
+        assert_eq!(tree.get(&9), Some(9));
+    }
+
+    #[test]
+    fn drop_reclaims_every_node() {
+        let mut weak_nodes = Vec::new();
+        {
+            let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+            for i in 0..2000 {
+                tree.insert(i, i * 2);
+            }
+            for i in 0..2000 {
+                let node = tree.find_node(&i).unwrap();
+                weak_nodes.push(Rc::downgrade(&node));
+            }
+        }
+
+        let leaked = weak_nodes.iter().filter(|w| w.upgrade().is_some()).count();
+        assert_eq!(leaked, 0, "{leaked} node(s) survived dropping the tree");
+    }
+
+    #[test]
+    fn get_after_insert() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 100);
+        assert_eq!(tree.get(&10), Some(100));
+        assert_eq!(tree.get(&99), None);
+    }
+
+    #[test]
+    fn insert_overwrites_value_on_duplicate_key() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 100);
+        tree.insert(10, 200);
+        assert_eq!(tree.get(&10), Some(200));
+    }
+
+    #[test]
+    fn contains_key_reflects_presence() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 100);
+        assert!(tree.contains_key(&10));
+        assert!(!tree.contains_key(&11));
+    }
+
+    #[test]
+    fn get_or_insert_inserts_default_when_missing() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        assert_eq!(tree.get_or_insert(10, 100), 100);
+        assert_eq!(tree.get(&10), Some(100));
+    }
+
+    #[test]
+    fn get_or_insert_returns_existing_value_without_overwriting() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 100);
+        assert_eq!(tree.get_or_insert(10, 999), 100);
+        assert_eq!(tree.get(&10), Some(100));
+    }
+
+    #[test]
+    fn validate_accepts_well_formed_tree() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n5 = leaf(&nil, 5, Color::Black);
+        let n20 = leaf(&nil, 20, Color::Black);
+
+        let root = Node::new(10, 10);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n20));
+        tree.root = Some(root);
+
+        assert_eq!(tree.validate(), Ok(3));
+    }
+
+    #[test]
+    fn validate_detects_red_red_violation() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n3 = leaf(&nil, 3, Color::Red);
+        let n5 = Node::new(5, 5);
+        n5.borrow_mut().color = Color::Red;
+        RedBlackTree::<i32, i32>::set_child(&n5, Side::Left, Some(n3));
+        n5.borrow_mut().right = Some(nil.clone());
+
+        let n20 = leaf(&nil, 20, Color::Black);
+
+        let root = Node::new(10, 10);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n20));
+        tree.root = Some(root);
+
+        let err = tree.validate().unwrap_err();
+        assert!(err.contains("red-red violation"), "{err}");
+        assert!(err.contains('5'), "{err}");
+        assert!(err.contains('3'), "{err}");
+    }
+
+    #[test]
+    fn validate_detects_black_height_mismatch() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        let nil = tree.nil.clone();
+
+        let n5 = leaf(&nil, 5, Color::Black);
+
+        let n15 = leaf(&nil, 15, Color::Black);
+        let n25 = leaf(&nil, 25, Color::Black);
+        let n20 = Node::new(20, 20);
+        n20.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&n20, Side::Left, Some(n15));
+        RedBlackTree::<i32, i32>::set_child(&n20, Side::Right, Some(n25));
+
+        let root = Node::new(10, 10);
+        root.borrow_mut().color = Color::Black;
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Left, Some(n5));
+        RedBlackTree::<i32, i32>::set_child(&root, Side::Right, Some(n20));
+        tree.root = Some(root);
+
+        let err = tree.validate().unwrap_err();
+        assert!(err.contains("black-height mismatch"), "{err}");
+        assert!(err.contains("key 10"), "{err}");
+    }
+
+    #[test]
+    fn iter_yields_pairs_in_ascending_key_order() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k * 2);
+        }
+        assert_eq!(
+            tree.iter().collect::<Vec<_>>(),
+            vec![(5, 10), (10, 20), (15, 30), (20, 40), (25, 50), (30, 60), (35, 70)]
+        );
+    }
+
+    #[test]
+    fn level_order_yields_pairs_rank_by_rank() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        let keys: Vec<i32> = tree.level_order().map(|(k, _)| k).collect();
+        assert_eq!(keys[0], 20);
+        assert_eq!(keys[1..3].iter().collect::<std::collections::BTreeSet<_>>(), [&10, &30].into_iter().collect());
+        assert_eq!(
+            keys[3..7].iter().collect::<std::collections::BTreeSet<_>>(),
+            [&5, &15, &25, &35].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn range_respects_inclusive_bounds() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        let keys: Vec<i32> = tree
+            .range(Bound::Included(&10), Bound::Included(&25))
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys, vec![10, 15, 20, 25]);
+    }
+
+    #[test]
+    fn range_respects_exclusive_bounds() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        let keys: Vec<i32> = tree
+            .range(Bound::Excluded(&10), Bound::Excluded(&25))
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys, vec![15, 20]);
+    }
+
+    #[test]
+    fn range_unbounded_yields_every_pair() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        let keys: Vec<i32> = tree
+            .range(Bound::Unbounded, Bound::Unbounded)
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys, vec![5, 10, 15, 20, 25, 30, 35]);
+    }
+
+    #[test]
+    fn successor_and_predecessor_walk_ascending_order() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        assert_eq!(tree.successor(&15), Some(20));
+        assert_eq!(tree.successor(&35), None);
+        assert_eq!(tree.predecessor(&15), Some(10));
+        assert_eq!(tree.predecessor(&5), None);
+    }
+
+    #[test]
+    fn successor_and_predecessor_return_none_for_missing_key() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 10);
+        assert_eq!(tree.successor(&42), None);
+        assert_eq!(tree.predecessor(&42), None);
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_distinct_keys() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        assert_eq!(tree.lowest_common_ancestor(&5, &15), Some(10));
+        assert_eq!(tree.lowest_common_ancestor(&5, &35), Some(20));
+        assert_eq!(tree.lowest_common_ancestor(&25, &35), Some(30));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_ancestor_and_descendant_is_the_ancestor() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30, 5, 15, 25, 35] {
+            tree.insert(k, k);
+        }
+        assert_eq!(tree.lowest_common_ancestor(&10, &15), Some(10));
+        assert_eq!(tree.lowest_common_ancestor(&15, &10), Some(10));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_of_same_key_is_itself() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        for k in [20, 10, 30] {
+            tree.insert(k, k);
+        }
+        assert_eq!(tree.lowest_common_ancestor(&10, &10), Some(10));
+    }
+
+    #[test]
+    fn lowest_common_ancestor_returns_none_for_missing_key() {
+        let mut tree: RedBlackTree<i32, i32> = RedBlackTree::new();
+        tree.insert(10, 10);
+        assert_eq!(tree.lowest_common_ancestor(&10, &42), None);
+        assert_eq!(tree.lowest_common_ancestor(&42, &10), None);
+    }
+
+    #[test]
+    fn from_iter_and_into_iter_round_trip() {
+        let pairs = vec![(3, "c"), (1, "a"), (2, "b")];
+        let tree: RedBlackTree<i32, &str> = pairs.clone().into_iter().collect();
+        assert!(tree.validate().is_ok());
+
+        let mut collected: Vec<_> = tree.into_iter().collect();
+        collected.sort();
+        let mut expected = pairs;
+        expected.sort();
+        assert_eq!(collected, expected);
+    }
 }